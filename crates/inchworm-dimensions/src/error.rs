@@ -9,3 +9,40 @@ pub enum DimensionError {
     #[error("zero denominator in exponent")]
     ZeroDenominator,
 }
+
+impl DimensionError {
+    /// Stable, machine-readable code identifying the error variant.
+    ///
+    /// Codes are never reassigned, so they can be used to filter logs and
+    /// link to documentation independently of the `Display` message.
+    ///
+    /// # Examples
+    /// ```
+    /// use inchworm_dimensions::Exp;
+    ///
+    /// let err = Exp::new(1, 0).unwrap_err();
+    /// assert_eq!(err.code(), "IWD0002");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ExponentOverflow => "IWD0001",
+            Self::ZeroDenominator => "IWD0002",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_are_stable() {
+        let cases = [
+            (DimensionError::ExponentOverflow, "IWD0001"),
+            (DimensionError::ZeroDenominator, "IWD0002"),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.code(), expected, "unexpected code for {err:?}");
+        }
+    }
+}